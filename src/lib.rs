@@ -1,11 +1,72 @@
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::DeserializeOwned;
 use serde::ser::SerializeSeq;
-use reqwest::{Client, Error};
+use reqwest::Client;
+use ethers::types::{Address, U256};
+use thiserror::Error;
+use futures::stream::{self, Stream, TryStreamExt};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use async_trait::async_trait;
+
+// One gwei expressed in wei, for converting the gas tracker's gwei tiers.
+const GWEI: u64 = 1_000_000_000;
+
+// Convert a (possibly fractional) gwei price into wei.
+fn gwei_to_wei(gwei: f64) -> U256 {
+    U256::from((gwei * GWEI as f64).round() as u128)
+}
+
+// Parse a decimal-string scalar `result` into a `U256`, surfacing an unexpected
+// payload as an error instead of masking it as a zero value.
+fn parse_u256(raw: &str) -> Result<U256, EtherscanError> {
+    U256::from_dec_str(raw).map_err(|_| EtherscanError::BadStatus(raw.to_owned()))
+}
+
+// Etherscan caps `offset` at 10000 rows per page; the streaming helpers request
+// full pages and stop once a short page comes back.
+const DEFAULT_PAGE_OFFSET: i64 = 10000;
+
+// Free-tier Etherscan keys are limited to 5 requests/second.
+const DEFAULT_REQUESTS_PER_SECOND: u32 = 5;
+
+// How many times `get` retries a request that trips the rate limit before
+// giving up and surfacing `RateLimitExceeded`.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
 
 
 const API_URL: &'static str = "https://api.etherscan.io/api";
 const GOERLI_API_URL: &'static str = "https://api-goerli.etherscan.io/api";
 const SEPOLIA_API_URL: &'static str = "https://api-sepolia.etherscan.io/api";
+const POLYGON_API_URL: &'static str = "https://api.polygonscan.com/api";
+const BSC_API_URL: &'static str = "https://api.bscscan.com/api";
+const ARBITRUM_API_URL: &'static str = "https://api.arbiscan.io/api";
+
+// The Etherscan-family explorer to target. Every deployment exposes the same
+// `module`/`action` API, so selecting a chain only swaps the base URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chain {
+    Mainnet,
+    Goerli,
+    Sepolia,
+    Polygon,
+    Bsc,
+    Arbitrum,
+}
+
+impl Chain {
+    fn base_url(&self) -> &'static str {
+        match self {
+            Chain::Mainnet => API_URL,
+            Chain::Goerli => GOERLI_API_URL,
+            Chain::Sepolia => SEPOLIA_API_URL,
+            Chain::Polygon => POLYGON_API_URL,
+            Chain::Bsc => BSC_API_URL,
+            Chain::Arbitrum => ARBITRUM_API_URL,
+        }
+    }
+}
 
 // region: API Request structs
 
@@ -182,6 +243,46 @@ impl Serialize for EventLogTopicPaginatedQuery<'_> {
 
 // region: Geth/Parity proxy endpoints
 
+// EIP-1898 block selector: either a named tag or a numeric height. Serializes
+// to the string the proxy endpoints expect (`"latest"` or a `0x`-hex height).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockId {
+    Earliest,
+    Latest,
+    Pending,
+    Number(u64),
+}
+
+impl BlockId {
+    fn to_tag(&self) -> String {
+        match self {
+            BlockId::Earliest => "earliest".to_owned(),
+            BlockId::Latest => "latest".to_owned(),
+            BlockId::Pending => "pending".to_owned(),
+            BlockId::Number(n) => format!("0x{:x}", n),
+        }
+    }
+}
+
+impl From<i64> for BlockId {
+    fn from(blockno: i64) -> Self {
+        BlockId::Number(blockno as u64)
+    }
+}
+
+impl From<u64> for BlockId {
+    fn from(blockno: u64) -> Self {
+        BlockId::Number(blockno)
+    }
+}
+
+impl Serialize for BlockId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where S: Serializer {
+        serializer.serialize_str(&self.to_tag())
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct BlockNumberBoolQuery<'a> {
     tag: &'a str,  // block number in hex
@@ -302,29 +403,382 @@ struct BlockchainSizeQuery<'a> {
 // endregion
 
 
+// region: API Response structs
+
+// Standard Etherscan envelope: `{ "status", "message", "result" }`. The inner
+// `result` is itself JSON (an object or array) for most endpoints, so `get`
+// deserializes it into the endpoint's domain type `T`.
 #[derive(Debug, Deserialize)]
-struct ApiResponse {
+struct Response<T> {
     status: String,
-    result: String,
+    message: String,
+    result: T,
+}
+
+// Errors surfaced by the client. Etherscan signals failures in-band (HTTP 200
+// with `status == "0"`), so `get` inspects the envelope and maps the known
+// result strings to dedicated variants before falling back to `ApiError`.
+#[derive(Debug, Error)]
+pub enum EtherscanError {
+    #[error(transparent)]
+    Transport(#[from] reqwest::Error),
+    #[error(transparent)]
+    Deserialization(#[from] serde_json::Error),
+    #[error("Etherscan rate limit reached")]
+    RateLimitExceeded,
+    #[error("invalid API key")]
+    InvalidApiKey,
+    // Recoverable: the query succeeded but matched no rows (e.g. an address with
+    // no transactions, or an empty NFT inventory). Callers can treat it as an
+    // empty list rather than a hard failure.
+    #[error("no results found")]
+    EmptyResult,
+    #[error("Etherscan returned a bad status: {0}")]
+    BadStatus(String),
+    #[error("Etherscan API error (status {status}): {message} ({result})")]
+    ApiError {
+        status: String,
+        message: String,
+        result: String,
+    },
+}
+
+// region: Accounts and Transactions response structs
+
+#[derive(Debug, Deserialize)]
+pub struct AccountBalance {
+    pub account: Address,
+    pub balance: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalTransaction {
+    pub block_number: String,
+    pub time_stamp: String,
+    pub hash: String,
+    pub nonce: String,
+    pub block_hash: String,
+    pub transaction_index: String,
+    pub from: String,
+    pub to: String,
+    pub value: String,
+    pub gas: String,
+    pub gas_price: String,
+    pub is_error: String,
+    #[serde(rename = "txreceipt_status")]
+    pub txreceipt_status: String,
+    pub input: String,
+    pub contract_address: String,
+    pub cumulative_gas_used: String,
+    pub gas_used: String,
+    pub confirmations: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransaction {
+    pub block_number: String,
+    pub time_stamp: String,
+    pub hash: String,
+    pub from: String,
+    pub to: String,
+    pub value: String,
+    pub contract_address: String,
+    pub input: String,
+    #[serde(rename = "type")]
+    pub tx_type: String,
+    pub gas: String,
+    pub gas_used: String,
+    pub trace_id: String,
+    pub is_error: String,
+    pub err_code: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Erc20TransferEvent {
+    pub block_number: String,
+    pub time_stamp: String,
+    pub hash: String,
+    pub nonce: String,
+    pub block_hash: String,
+    pub from: String,
+    pub contract_address: String,
+    pub to: String,
+    pub value: String,
+    pub token_name: String,
+    pub token_symbol: String,
+    pub token_decimal: String,
+    pub transaction_index: String,
+    pub gas: String,
+    pub gas_price: String,
+    pub gas_used: String,
+    pub cumulative_gas_used: String,
+    pub input: String,
+    pub confirmations: String,
+}
+
+// endregion
+
+// region: Contracts response structs
+
+// Some verified-contract fields (notably the proxy `Implementation`) come back
+// as an empty string when absent or the literal `"GENESIS"` sentinel for
+// genesis-block contracts rather than a real value.
+#[derive(Debug)]
+pub enum GenesisOption<T> {
+    None,
+    Genesis,
+    Some(T),
+}
+
+impl<'de, T> Deserialize<'de> for GenesisOption<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: String = String::deserialize(deserializer)?;
+        if raw.is_empty() {
+            Ok(GenesisOption::None)
+        } else if raw.starts_with("GENESIS") {
+            Ok(GenesisOption::Genesis)
+        } else {
+            raw.parse().map(GenesisOption::Some).map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContractMetadata {
+    pub source_code: String,
+    #[serde(rename = "ABI")]
+    pub abi: String,
+    pub contract_name: String,
+    pub compiler_version: String,
+    pub optimization_used: String,
+    pub runs: String,
+    pub constructor_arguments: String,
+    #[serde(rename = "EVMVersion")]
+    pub evm_version: String,
+    pub library: String,
+    pub license_type: String,
+    pub proxy: String,
+    pub implementation: GenesisOption<Address>,
+    pub swarm_source: String,
+}
+
+// endregion
+
+// region: Logs response structs
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLog {
+    pub address: String,
+    pub topics: Vec<String>,
+    pub data: String,
+    pub block_number: String,
+    pub time_stamp: String,
+    pub gas_price: String,
+    pub gas_used: String,
+    pub log_index: String,
+    pub transaction_hash: String,
+    pub transaction_index: String,
+}
+
+// endregion
+
+// region: Tokens and Stats response structs
+
+// Several stats/token fields are decimal numbers delivered inside JSON strings
+// (e.g. supply figures, gas prices); these helpers parse them into real numbers.
+fn deserialize_number_from_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let raw: String = String::deserialize(deserializer)?;
+    raw.parse().map_err(serde::de::Error::custom)
+}
+
+fn deserialize_u256_from_string<'de, D>(deserializer: D) -> Result<U256, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw: String = String::deserialize(deserializer)?;
+    U256::from_dec_str(&raw).map_err(serde::de::Error::custom)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenInfo {
+    pub contract_address: String,
+    pub token_name: String,
+    pub symbol: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub divisor: u64,
+    pub token_type: String,
+    pub total_supply: String,
+    pub website: String,
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TokenHolder {
+    pub token_holder_address: String,
+    pub token_holder_quantity: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TokenBalance {
+    pub token_address: String,
+    pub token_name: String,
+    pub token_symbol: String,
+    pub token_quantity: String,
+    pub token_divisor: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TokenInventoryItem {
+    pub token_address: String,
+    pub token_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EthPrice {
+    #[serde(rename = "ethbtc", deserialize_with = "deserialize_number_from_string")]
+    pub eth_btc: f64,
+    #[serde(rename = "ethbtc_timestamp", deserialize_with = "deserialize_number_from_string")]
+    pub eth_btc_timestamp: u64,
+    #[serde(rename = "ethusd", deserialize_with = "deserialize_number_from_string")]
+    pub eth_usd: f64,
+    #[serde(rename = "ethusd_timestamp", deserialize_with = "deserialize_number_from_string")]
+    pub eth_usd_timestamp: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GasOracle {
+    pub last_block: String,
+    // Tier prices are decimal gwei strings; non-mainnet hosts report fractional
+    // gwei (e.g. "0.1", "30.6"), so parse them as floats rather than integers.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub safe_gas_price: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub propose_gas_price: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub fast_gas_price: f64,
+    #[serde(rename = "suggestBaseFee", deserialize_with = "deserialize_number_from_string")]
+    pub suggest_base_fee: f64,
+    #[serde(rename = "gasUsedRatio")]
+    pub gas_used_ratio: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EthSupply {
+    #[serde(deserialize_with = "deserialize_u256_from_string")]
+    pub eth_supply: U256,
+    #[serde(deserialize_with = "deserialize_u256_from_string")]
+    pub eth2_staking: U256,
+    #[serde(deserialize_with = "deserialize_u256_from_string")]
+    pub burnt_fees: U256,
+    #[serde(deserialize_with = "deserialize_u256_from_string")]
+    pub withdrawn_total: U256,
+}
+
+// endregion
+
+// endregion
+
+// Leaky-bucket throttle that spaces outgoing requests by a minimum interval so
+// a tight loop (or a long pagination stream) stays under Etherscan's cap.
+struct RateLimiter {
+    min_interval: Duration,
+    last: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: u32) -> Self {
+        let per_second: f64 = requests_per_second.max(1) as f64;
+        RateLimiter {
+            min_interval: Duration::from_secs_f64(1.0 / per_second),
+            last: Mutex::new(None),
+        }
+    }
+
+    // Block until enough time has elapsed since the previous request.
+    async fn acquire(&self) {
+        let mut last = self.last.lock().await;
+        if let Some(prev) = *last {
+            let elapsed: Duration = prev.elapsed();
+            if elapsed < self.min_interval {
+                tokio::time::sleep(self.min_interval - elapsed).await;
+            }
+        }
+        *last = Some(Instant::now());
+    }
 }
 
 // Create a struct to hold the API key and the HTTP client
-struct AsyncClient {
+pub struct AsyncClient {
     api_key: String,
     client: Client,
+    base_url: &'static str,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    gas_multiplier: f64,
+    gas_ceiling: Option<U256>,
 }
 
 // Init
 impl AsyncClient {
-    // Method to create a new instance of EtherscanClient with the API key
-    fn new(api_key: &str) -> Self {
+    // Method to create a new instance of EtherscanClient with the API key,
+    // targeting Ethereum mainnet.
+    pub fn new(api_key: &str) -> Self {
+        AsyncClient::with_chain(api_key, Chain::Mainnet)
+    }
+
+    // Method to create a client targeting a specific Etherscan-family chain.
+    // Defaults to the free-tier rate limit of 5 requests/second; use the
+    // `rate_limited` builder to change it.
+    pub fn with_chain(api_key: &str, chain: Chain) -> Self {
         AsyncClient {
             api_key: api_key.to_owned(),
             client: Client::new(),
+            base_url: chain.base_url(),
+            rate_limiter: Some(Arc::new(RateLimiter::new(DEFAULT_REQUESTS_PER_SECOND))),
+            gas_multiplier: 1.0,
+            gas_ceiling: None,
         }
     }
 
-    async fn get(&self, module: &str, action: &str, params: impl Serialize) -> Result<String, Error> {
+    // Reconfigure the minimum spacing between outgoing requests to `per_second`
+    // calls/second (raise it on paid plans, or lower it to be conservative).
+    pub fn rate_limited(mut self, per_second: u32) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(per_second)));
+        self
+    }
+
+    // Scale suggested gas prices by `multiplier` and cap them at `ceiling` (in
+    // wei) when used as a `GasOracleSource`. This bounds the `fetch` price and
+    // the EIP-1559 priority fee; the EIP-1559 max fee is not capped.
+    pub fn with_gas_limits(mut self, multiplier: f64, ceiling: Option<U256>) -> Self {
+        self.gas_multiplier = multiplier;
+        self.gas_ceiling = ceiling;
+        self
+    }
+
+    async fn get<T: DeserializeOwned>(&self, module: &str, action: &str, params: impl Serialize) -> Result<T, EtherscanError> {
         // Create the JSON-RPC request
         let base_request: BaseApiRequest = BaseApiRequest {
             module,
@@ -332,18 +786,90 @@ impl AsyncClient {
             apikey: &self.api_key,
         };
 
-        // Send the request and await the response
-        let response: ApiResponse = self.client
-            .get(API_URL)
-            .query(&base_request)
-            .query(&params)
-            .send()
-            .await?
-            .json::<ApiResponse>()
-            .await?;
+        // Retry a handful of times if Etherscan reports the rate limit, backing
+        // off between attempts so long pagination runs complete unattended.
+        let mut attempt: u32 = 0;
+        loop {
+            // Throttle before sending if a rate limiter is configured.
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+
+            // Parse the outer envelope first, keeping `result` as raw JSON so we
+            // can distinguish an error payload (a bare string) from a typed success.
+            let response: Response<serde_json::Value> = self.client
+                .get(self.base_url)
+                .query(&base_request)
+                .query(&params)
+                .send()
+                .await?
+                .json::<Response<serde_json::Value>>()
+                .await?;
+
+            if response.status != "1" {
+                let result: String = response.result.as_str().unwrap_or_default().to_owned();
+                // A status-0 response with a "no records" message (or an empty
+                // array payload) is an empty result, not an error condition.
+                let is_empty: bool = response.message.contains("No transactions found")
+                    || response.message.contains("No records found")
+                    || response.result.as_array().map_or(false, |a| a.is_empty());
+                let error: EtherscanError = match result.as_str() {
+                    _ if result.contains("rate limit reached") => EtherscanError::RateLimitExceeded,
+                    _ if response.message == "NOTOK"
+                        && result.to_lowercase().contains("invalid api key") =>
+                    {
+                        EtherscanError::InvalidApiKey
+                    }
+                    _ if is_empty => EtherscanError::EmptyResult,
+                    _ if response.message == "NOTOK" => EtherscanError::BadStatus(result),
+                    _ => EtherscanError::ApiError {
+                        status: response.status,
+                        message: response.message,
+                        result,
+                    },
+                };
+
+                if matches!(error, EtherscanError::RateLimitExceeded) && attempt < MAX_RATE_LIMIT_RETRIES {
+                    attempt += 1;
+                    tokio::time::sleep(Duration::from_millis(200 * 2u64.pow(attempt))).await;
+                    continue;
+                }
+                return Err(error);
+            }
+
+            // Status is "1": deserialize the inner `result` into the domain type.
+            return Ok(serde_json::from_value(response.result)?);
+        }
+    }
 
-        // Extract the balance from the response and return it
-        Ok(response.result.to_string())
+    // Turn a page-based endpoint into a flat stream. `request` is called with an
+    // increasing page number (starting at 1) and a fixed `offset`; the stream
+    // ends once a page returns fewer than `offset` items (including an empty
+    // page), so callers never have to hand-roll the termination check.
+    fn paginate<'a, T, Fut, F>(&'a self, offset: i64, request: F) -> impl Stream<Item = Result<T, EtherscanError>> + 'a
+    where
+        T: 'a,
+        Fut: std::future::Future<Output = Result<Vec<T>, EtherscanError>> + 'a,
+        F: Fn(i64, i64) -> Fut + 'a,
+    {
+        stream::try_unfold(Some(1_i64), move |state| {
+            let request = &request;
+            async move {
+                let page: i64 = match state {
+                    Some(page) => page,
+                    None => return Ok(None),
+                };
+                // An empty-result response marks the end of the data, not a failure.
+                let items: Vec<T> = match request(page, offset).await {
+                    Ok(items) => items,
+                    Err(EtherscanError::EmptyResult) => return Ok(None),
+                    Err(err) => return Err(err),
+                };
+                let next: Option<i64> = if (items.len() as i64) < offset { None } else { Some(page + 1) };
+                Ok(Some((stream::iter(items.into_iter().map(Ok)), next)))
+            }
+        })
+        .try_flatten()
     }
 
 }
@@ -351,15 +877,16 @@ impl AsyncClient {
 // Accounts API
 // TODO: Make this a trait
 impl AsyncClient {
-    async fn get_balance(&self, address: &str) -> Result<String, Error> {
+    pub async fn get_balance(&self, address: &str) -> Result<U256, EtherscanError> {
         let params: AddressTagQuery = AddressTagQuery {
             address,
             tag: "latest",
         };
-        self.get("account", "balance", params).await
+        let balance: String = self.get("account", "balance", params).await?;
+        parse_u256(&balance)
     }
 
-    async fn get_balance_multi(&self, addresses: &Vec<&str>) -> Result<String, Error> {
+    pub async fn get_balance_multi(&self, addresses: &Vec<&str>) -> Result<Vec<AccountBalance>, EtherscanError> {
         let addresses: String = addresses.join(",");
         let params: AddressTagQuery = AddressTagQuery {
             address: &addresses,
@@ -368,7 +895,7 @@ impl AsyncClient {
         self.get("account", "balancemulti", params).await
     }
 
-    async fn get_tx_list(&self, address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn get_tx_list(&self, address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<Vec<NormalTransaction>, EtherscanError> {
         let params: TxListPaginatedQuery<'_> = TxListPaginatedQuery {
             address,
             startblock: start_block,
@@ -380,7 +907,7 @@ impl AsyncClient {
         self.get("account", "txlist", params).await
     }
 
-    async fn get_tx_list_internal(&self, address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn get_tx_list_internal(&self, address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<Vec<InternalTransaction>, EtherscanError> {
         let params: TxListPaginatedQuery<'_> = TxListPaginatedQuery {
             address,
             startblock: start_block,
@@ -392,14 +919,14 @@ impl AsyncClient {
         self.get("account", "txlistinternal", params).await
     }
 
-    async fn get_tx_list_internal_by_hash(&self, tx_hash: &str) -> Result<String, Error> {
+    pub async fn get_tx_list_internal_by_hash(&self, tx_hash: &str) -> Result<String, EtherscanError> {
         let params: TxHashQuery<'_> = TxHashQuery {
             txhash: tx_hash,
         };
         self.get("account", "txlistinternal", params).await
     }
 
-    async fn get_tx_list_internal_by_blockrange(&self, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn get_tx_list_internal_by_blockrange(&self, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, EtherscanError> {
         let params: BlockRangePaginatedQuery<'_> = BlockRangePaginatedQuery {
             startblock: start_block,
             endblock: end_block,
@@ -410,7 +937,7 @@ impl AsyncClient {
         self.get("account", "txlistinternal", params).await
     }
 
-    async fn get_erc20_transfer_events(&self, address: &str, contract_address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn get_erc20_transfer_events(&self, address: &str, contract_address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<Vec<Erc20TransferEvent>, EtherscanError> {
         let params: TokenEventsPaginatedQuery<'_> = TokenEventsPaginatedQuery {
             address,
             contractaddress: contract_address,
@@ -423,7 +950,7 @@ impl AsyncClient {
         self.get("account", "tokentx", params).await
     }
 
-    async fn get_erc721_transfer_events(&self, address: &str, contract_address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn get_erc721_transfer_events(&self, address: &str, contract_address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, EtherscanError> {
         let params: TokenEventsPaginatedQuery<'_> = TokenEventsPaginatedQuery {
             address,
             contractaddress: contract_address,
@@ -436,7 +963,7 @@ impl AsyncClient {
         self.get("account", "tokennfttx", params).await
     }
 
-    async fn get_erc1155_transfer_events(&self, address: &str, contract_address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn get_erc1155_transfer_events(&self, address: &str, contract_address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, EtherscanError> {
         let params: TokenEventsPaginatedQuery<'_> = TokenEventsPaginatedQuery {
             address,
             contractaddress: contract_address,
@@ -449,7 +976,7 @@ impl AsyncClient {
         self.get("account", "tokennfttx", params).await
     }
 
-    async fn get_mined_blocks(&self, address: &str, blocktype: &str, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn get_mined_blocks(&self, address: &str, blocktype: &str, page: i64, offset: i64, sort: &str) -> Result<String, EtherscanError> {
         let params: AddressBlocktypePaginatedQuery<'_> = AddressBlocktypePaginatedQuery {
             address,
             blocktype,
@@ -460,7 +987,7 @@ impl AsyncClient {
         self.get("account", "getminedblocks", params).await
     }
 
-    async fn get_tx_list_beacon_withdrawal(&self, address: &str, contract_address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn get_tx_list_beacon_withdrawal(&self, address: &str, contract_address: &str, start_block: i64, end_block: i64, page: i64, offset: i64, sort: &str) -> Result<String, EtherscanError> {
         let params: ContractByAddressBlockRangePaginatedQuery<'_> = ContractByAddressBlockRangePaginatedQuery {
             address,
             contractaddress: contract_address,
@@ -473,7 +1000,7 @@ impl AsyncClient {
         self.get("account", "txlistbeacon", params).await
     }
 
-    async fn get_balance_history(&self, address: &str, blockno: i64) -> Result<String, Error> {
+    pub async fn get_balance_history(&self, address: &str, blockno: i64) -> Result<String, EtherscanError> {
         let params: AddressBlockNumberQuery<'_> = AddressBlockNumberQuery {
             address,
             blockno
@@ -482,24 +1009,57 @@ impl AsyncClient {
     }
 }
 
+// Streaming wrappers over the paginated account/log endpoints. Each returns an
+// `impl Stream` that walks every page internally, so scraping a full history is
+// a single `.try_collect()` instead of a manual `page += 1` loop.
+impl AsyncClient {
+    pub fn get_tx_list_stream<'a>(&'a self, address: &'a str, start_block: i64, end_block: i64, sort: &'a str) -> impl Stream<Item = Result<NormalTransaction, EtherscanError>> + 'a {
+        self.paginate(DEFAULT_PAGE_OFFSET, move |page, offset| {
+            self.get_tx_list(address, start_block, end_block, page, offset, sort)
+        })
+    }
+
+    pub fn get_tx_list_internal_stream<'a>(&'a self, address: &'a str, start_block: i64, end_block: i64, sort: &'a str) -> impl Stream<Item = Result<InternalTransaction, EtherscanError>> + 'a {
+        self.paginate(DEFAULT_PAGE_OFFSET, move |page, offset| {
+            self.get_tx_list_internal(address, start_block, end_block, page, offset, sort)
+        })
+    }
+
+    pub fn get_erc20_transfer_events_stream<'a>(&'a self, address: &'a str, contract_address: &'a str, start_block: i64, end_block: i64, sort: &'a str) -> impl Stream<Item = Result<Erc20TransferEvent, EtherscanError>> + 'a {
+        self.paginate(DEFAULT_PAGE_OFFSET, move |page, offset| {
+            self.get_erc20_transfer_events(address, contract_address, start_block, end_block, page, offset, sort)
+        })
+    }
+
+    pub fn logs_by_address_stream<'a>(&'a self, address: &'a str, from_block: i64, to_block: i64) -> impl Stream<Item = Result<EventLog, EtherscanError>> + 'a {
+        self.paginate(DEFAULT_PAGE_OFFSET, move |page, offset| {
+            self.logs_by_address(address, from_block, to_block, page, offset)
+        })
+    }
+}
+
 // Contract API
 // TODO: Make this a trait
 impl AsyncClient {
-    async fn contract_abi(&self, address: &str) -> Result<String, Error> {
+    pub async fn contract_abi(&self, address: &str) -> Result<ethabi::Abi, EtherscanError> {
         let params: AddressQuery<'_> = AddressQuery {
             address,
         };
-        self.get("contract", "getabi", params).await
+        // The `result` is the ABI as a JSON-encoded string; parse it into an `Abi`.
+        let abi: String = self.get("contract", "getabi", params).await?;
+        Ok(serde_json::from_str(&abi)?)
     }
 
-    async fn contract_source_code(&self, address: &str) -> Result<String, Error> {
+    pub async fn contract_source_code(&self, address: &str) -> Result<ContractMetadata, EtherscanError> {
         let params: AddressQuery<'_> = AddressQuery {
             address,
         };
-        self.get("contract", "getsourcecode", params).await
+        // `getsourcecode` always returns a single-element array.
+        let sources: Vec<ContractMetadata> = self.get("contract", "getsourcecode", params).await?;
+        sources.into_iter().next().ok_or(EtherscanError::EmptyResult)
     }
 
-    async fn contract_creation(&self, contract_addresses: &Vec<&str>) -> Result<String, Error> {
+    pub async fn contract_creation(&self, contract_addresses: &Vec<&str>) -> Result<String, EtherscanError> {
         let contract_addresses: String = contract_addresses.join(",");
         let params: ContractAddressesQuery<'_> = ContractAddressesQuery {
             contractaddresses: &contract_addresses,
@@ -510,14 +1070,14 @@ impl AsyncClient {
 
 // Transaction API
 impl AsyncClient {
-    async fn transaction_status(&self, tx_hash: &str) -> Result<String, Error> {
+    pub async fn transaction_status(&self, tx_hash: &str) -> Result<String, EtherscanError> {
         let params: TxHashQuery<'_> = TxHashQuery {
             txhash: tx_hash,
         };
         self.get("transaction", "getstatus", params).await
     }
 
-    async fn transaction_receipt_status(&self, tx_hash: &str) -> Result<String, Error> {
+    pub async fn transaction_receipt_status(&self, tx_hash: &str) -> Result<String, EtherscanError> {
         let params: TxHashQuery<'_> = TxHashQuery {
             txhash: tx_hash,
         };
@@ -527,21 +1087,21 @@ impl AsyncClient {
 
 // Block API
 impl AsyncClient {
-    async fn block_reward(&self, blockno: i64) -> Result<String, Error> {
+    pub async fn block_reward(&self, blockno: i64) -> Result<String, EtherscanError> {
         let params: BlockNumberQuery = BlockNumberQuery {
             blockno
         };
         self.get("block", "getblockreward", params).await
     }
 
-    async fn block_countdown(&self, blockno: i64) -> Result<String, Error> {
+    pub async fn block_countdown(&self, blockno: i64) -> Result<String, EtherscanError> {
         let params: BlockNumberQuery = BlockNumberQuery {
             blockno
         };
         self.get("block", "getblockcountdown", params).await
     }
     
-    async fn block_number_by_timestamp(&self, timestamp: i64, closest: &str) -> Result<String, Error> {
+    pub async fn block_number_by_timestamp(&self, timestamp: i64, closest: &str) -> Result<String, EtherscanError> {
         let params: BlockTimestampQuery<'_> = BlockTimestampQuery {
             timestamp,
             closest
@@ -549,7 +1109,7 @@ impl AsyncClient {
         self.get("block", "getblocknobytime", params).await
     }
 
-    async fn daily_average_blocksize(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_average_blocksize(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -558,7 +1118,7 @@ impl AsyncClient {
         self.get("block", "getdailyavgblocksize", params).await
     }
 
-    async fn daily_block_count(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_block_count(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -567,7 +1127,7 @@ impl AsyncClient {
         self.get("block", "getdailyblockcount", params).await
     }
 
-    async fn daily_block_rewards(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_block_rewards(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -576,7 +1136,7 @@ impl AsyncClient {
         self.get("block", "getdailyblockrewards", params).await
     }
 
-    async fn daily_block_time(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_block_time(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -585,7 +1145,7 @@ impl AsyncClient {
         self.get("block", "getdailyblocktime", params).await
     }
 
-    async fn daily_uncle_block_count(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_uncle_block_count(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -599,7 +1159,7 @@ impl AsyncClient {
 
 // Logs API
 impl AsyncClient {
-    async fn logs_by_address(&self, address: &str, from_block: i64, to_block: i64, page: i64, offset: i64) -> Result<String, Error> {
+    pub async fn logs_by_address(&self, address: &str, from_block: i64, to_block: i64, page: i64, offset: i64) -> Result<Vec<EventLog>, EtherscanError> {
         let params: EventLogAddressPaginatedQuery<'_> = EventLogAddressPaginatedQuery {
             address,
             fromblock: from_block,
@@ -610,7 +1170,7 @@ impl AsyncClient {
         self.get("logs", "getLogs", params).await
     }
 
-    // async fn logs_by_topic(&self, topic0: &str, topic1: &str, topic2: &str, topic3: &str, from_block: i64, to_block: i64, page: i64, offset: i64) -> Result<String, Error> {
+    // async fn logs_by_topic(&self, topic0: &str, topic1: &str, topic2: &str, topic3: &str, from_block: i64, to_block: i64, page: i64, offset: i64) -> Result<String, EtherscanError> {
     //     let params: EventLogTopicPaginatedQuery<'_> = EventLogTopicPaginatedQuery {
     //         topic0,
     //         topic1,
@@ -624,7 +1184,7 @@ impl AsyncClient {
     //     self.get("logs", "getLogs", params).await
     // }
 
-    // async fn logs_by_address_topic(&self, address: &str, topic0: &str, topic1: &str, topic2: &str, topic3: &str, from_block: i64, to_block: i64, page: i64, offset: i64) -> Result<String, Error> {
+    // async fn logs_by_address_topic(&self, address: &str, topic0: &str, topic1: &str, topic2: &str, topic3: &str, from_block: i64, to_block: i64, page: i64, offset: i64) -> Result<String, EtherscanError> {
     //     let params: EventLogAddressTopicPaginatedQuery<'_> = EventLogAddressTopicPaginatedQuery {
     //         address,
     //         topic0,
@@ -643,94 +1203,101 @@ impl AsyncClient {
 
 // Geth/Parity Proxy API
 impl AsyncClient {
-    async fn eth_get_block_number(&self) -> Result<String, Error> {
+    pub async fn eth_get_block_number(&self) -> Result<String, EtherscanError> {
         self.get("proxy", "eth_blockNumber", ()).await
     }
 
-    async fn eth_get_block_by_number(&self, blockno: i64, show_full_tx: bool) -> Result<String, Error> {
+    pub async fn eth_get_block_by_number(&self, block: impl Into<BlockId>, show_full_tx: bool) -> Result<String, EtherscanError> {
+        let tag: String = block.into().to_tag();
         let params: BlockNumberBoolQuery = BlockNumberBoolQuery {
-            tag: &format!("0x{:x}", blockno),
+            tag: &tag,
             boolean: show_full_tx
         };
         self.get("proxy", "eth_getBlockByNumber", params).await
     }
 
-    async fn eth_get_uncle_by_block_number_and_index(&self, blockno: i64, index: i64) -> Result<String, Error> {
+    pub async fn eth_get_uncle_by_block_number_and_index(&self, block: impl Into<BlockId>, index: i64) -> Result<String, EtherscanError> {
+        let tag: String = block.into().to_tag();
         let params: BlockNumberIndexQuery = BlockNumberIndexQuery {
-            tag: &format!("0x{:x}", blockno),
+            tag: &tag,
             index: &format!("0x{:x}", index),
         };
         self.get("proxy", "eth_getUncleByBlockNumberAndIndex", params).await
     }
 
-    async fn eth_get_transaction_by_hash(&self, tx_hash: &str) -> Result<String, Error> {
+    pub async fn eth_get_transaction_by_hash(&self, tx_hash: &str) -> Result<String, EtherscanError> {
         let params: TxHashQuery<'_> = TxHashQuery {
             txhash: tx_hash
         };
         self.get("proxy", "eth_getTransactionByHash", params).await
     }
 
-    async fn eth_get_transaction_by_block_number_and_index(&self, blockno: i64, index: i64) -> Result<String, Error> {
+    pub async fn eth_get_transaction_by_block_number_and_index(&self, block: impl Into<BlockId>, index: i64) -> Result<String, EtherscanError> {
+        let tag: String = block.into().to_tag();
         let params: BlockNumberIndexQuery = BlockNumberIndexQuery {
-            tag: &format!("0x{:x}", blockno),
+            tag: &tag,
             index: &format!("0x{:x}", index),
         };
         self.get("proxy", "eth_getTransactionByBlockNumberAndIndex", params).await
     }
 
-    async fn eth_get_transaction_count(&self, address: &str, tag: &str) -> Result<String, Error> {
+    pub async fn eth_get_transaction_count(&self, address: &str, block: impl Into<BlockId>) -> Result<String, EtherscanError> {
+        let tag: String = block.into().to_tag();
         let params: AddressTagQuery<'_> = AddressTagQuery {
             address,
-            tag,
+            tag: &tag,
         };
         self.get("proxy", "eth_getTransactionCount", params).await
     }
     
-    async fn eth_send_raw_transaction(&self, hex: &str) -> Result<String, Error> {
+    pub async fn eth_send_raw_transaction(&self, hex: &str) -> Result<String, EtherscanError> {
         let params: RawTxQuery<'_> = RawTxQuery {
             hex
         };
         self.get("proxy", "eth_sendRawTransaction", params).await
     }
 
-    async fn eth_get_transaction_receipt(&self, tx_hash: &str) -> Result<String, Error> {
+    pub async fn eth_get_transaction_receipt(&self, tx_hash: &str) -> Result<String, EtherscanError> {
         let params: TxHashQuery<'_> = TxHashQuery {
             txhash: tx_hash
         };
         self.get("proxy", "eth_getTransactionReceipt", params).await
     }
 
-    async fn eth_call(&self, to: &str, data: &str, tag: &str) -> Result<String, Error> {
+    pub async fn eth_call(&self, to: &str, data: &str, block: impl Into<BlockId>) -> Result<String, EtherscanError> {
+        let tag: String = block.into().to_tag();
         let params: CallQuery<'_> = CallQuery {
             to,
             data,
-            tag,
+            tag: &tag,
         };
         self.get("proxy", "eth_call", params).await
     }
 
-    async fn eth_get_code(&self, address: &str, tag: &str) -> Result<String, Error> {
+    pub async fn eth_get_code(&self, address: &str, block: impl Into<BlockId>) -> Result<String, EtherscanError> {
+        let tag: String = block.into().to_tag();
         let params: AddressTagQuery<'_> = AddressTagQuery {
             address,
-            tag,
+            tag: &tag,
         };
         self.get("proxy", "eth_getCode", params).await
     }
 
-    async fn eth_get_storage_at(&self, address: &str, position: &str, tag: &str) -> Result<String, Error> {
+    pub async fn eth_get_storage_at(&self, address: &str, position: &str, block: impl Into<BlockId>) -> Result<String, EtherscanError> {
+        let tag: String = block.into().to_tag();
         let params: StoragePositionQuery<'_> = StoragePositionQuery {
             address,
             position,
-            tag,
+            tag: &tag,
         };
         self.get("proxy", "eth_getStorageAt", params).await
     }
 
-    async fn eth_gas_price(&self) -> Result<String, Error> {
+    pub async fn eth_gas_price(&self) -> Result<String, EtherscanError> {
         self.get("proxy", "eth_gasPrice", ()).await
     }
 
-    async fn eth_estimate_gas(&self, to: &str, data: &str, value: i64, gas: i64, gas_price: i64) -> Result<String, Error> {
+    pub async fn eth_estimate_gas(&self, to: &str, data: &str, value: i64, gas: i64, gas_price: i64) -> Result<String, EtherscanError> {
         let params: EstimateGasQuery<'_> = EstimateGasQuery {
             to,
             data,
@@ -744,22 +1311,24 @@ impl AsyncClient {
 
 // Tokens API
 impl AsyncClient {
-    async fn token_total_supply(&self, contract_address: &str) -> Result<String, Error> {
+    pub async fn token_total_supply(&self, contract_address: &str) -> Result<U256, EtherscanError> {
         let params: ContractAddressQuery<'_> = ContractAddressQuery {
             contractaddress: contract_address,
         };
-        self.get("tokens", "tokenSupply", params).await
+        let supply: String = self.get("tokens", "tokenSupply", params).await?;
+        parse_u256(&supply)
     }
 
-    async fn token_balance(&self, contract_address: &str, address: &str, tag: &str) -> Result<String, Error> {
+    pub async fn token_balance(&self, contract_address: &str, address: &str, tag: &str) -> Result<U256, EtherscanError> {
         let params: ContractByAddressQuery<'_> = ContractByAddressQuery {
             contractaddress: contract_address,
             address,
         };
-        self.get("tokens", "tokenBalance", params).await
+        let balance: String = self.get("tokens", "tokenBalance", params).await?;
+        parse_u256(&balance)
     }
 
-    async fn token_supply_history(&self, contract_address: &str, blockno: i64, offset: i64, page: i64, sort: &str) -> Result<String, Error> {
+    pub async fn token_supply_history(&self, contract_address: &str, blockno: i64, offset: i64, page: i64, sort: &str) -> Result<String, EtherscanError> {
         let params: ContractByBlockNumberQuery<'_> = ContractByBlockNumberQuery {
             contractaddress: contract_address,
             blockno: blockno,
@@ -767,7 +1336,7 @@ impl AsyncClient {
         self.get("tokens", "tokenSupplyHistory", params).await
     }
 
-    async fn token_balance_history(&self, contract_address: &str, address: &str, blockno: i64, offset: i64, page: i64, sort: &str) -> Result<String, Error> {
+    pub async fn token_balance_history(&self, contract_address: &str, address: &str, blockno: i64, offset: i64, page: i64, sort: &str) -> Result<String, EtherscanError> {
         let params: ContractByAddressBlockNumberQuery<'_> = ContractByAddressBlockNumberQuery {
             contractaddress: contract_address,
             address,
@@ -776,7 +1345,7 @@ impl AsyncClient {
         self.get("tokens", "tokenBalanceHistory", params).await
     }
 
-    async fn token_holder_list(&self, contract_address: &str, page: i64, offset: i64, sort: &str) -> Result<String, Error> {
+    pub async fn token_holder_list(&self, contract_address: &str, page: i64, offset: i64, sort: &str) -> Result<Vec<TokenHolder>, EtherscanError> {
         let params: ContractAddressPaginatedQuery<'_> = ContractAddressPaginatedQuery {
             contractaddress: contract_address,
             page: page,
@@ -785,57 +1354,111 @@ impl AsyncClient {
         self.get("tokens", "tokennholderlist", params).await
     }
 
-    async fn token_info(&self, contract_address: &str) -> Result<String, Error> {
+    pub async fn token_info(&self, contract_address: &str) -> Result<TokenInfo, EtherscanError> {
         let params: ContractAddressQuery<'_> = ContractAddressQuery {
             contractaddress: contract_address,
         };
-        self.get("tokens", "tokenInfo", params).await
+        let infos: Vec<TokenInfo> = self.get("tokens", "tokenInfo", params).await?;
+        infos.into_iter().next().ok_or(EtherscanError::EmptyResult)
     }
 
-    async fn erc20_token_balance(&self, address: &str, page: i64, offset: i64) -> Result<String, Error> {
+    pub async fn token_info_raw(&self, contract_address: &str) -> Result<String, EtherscanError> {
+        let params: ContractAddressQuery<'_> = ContractAddressQuery {
+            contractaddress: contract_address,
+        };
+        let result: serde_json::Value = self.get("tokens", "tokenInfo", params).await?;
+        Ok(result.to_string())
+    }
+
+    pub async fn erc20_token_balance(&self, address: &str, page: i64, offset: i64) -> Result<Vec<TokenBalance>, EtherscanError> {
         let params: AddressPaginatedQuery<'_> = AddressPaginatedQuery {
             address,
             page,
             offset,
         };
-        self.get("tokens", "tokenBalance", params).await
+        self.get("account", "addresstokenbalance", params).await
     }
 
-    async fn erc721_token_inventory(&self, address: &str, page: i64, offset: i64) -> Result<String, Error> {
+    pub async fn erc721_token_inventory(&self, address: &str, page: i64, offset: i64) -> Result<Vec<TokenInventoryItem>, EtherscanError> {
         let params: AddressPaginatedQuery<'_> = AddressPaginatedQuery {
             address,
             page,
             offset,
         };
-        self.get("tokens", "tokennfttx", params).await
+        self.get("account", "addresstokennftinventory", params).await
     }
 
-    async fn erc721_token_inventory_by_contract(&self, contract_address: &str, address: &str, page: i64, offset: i64) -> Result<String, Error> {
+    pub async fn erc721_token_inventory_by_contract(&self, contract_address: &str, address: &str, page: i64, offset: i64) -> Result<Vec<TokenInventoryItem>, EtherscanError> {
         let params: ContractByAddressPaginatedQuery<'_> = ContractByAddressPaginatedQuery {
             contractaddress: contract_address,
             address,
             page,
             offset,
         };
-        self.get("tokens", "tokennfttx", params).await
+        self.get("account", "addresstokennftinventory", params).await
+    }
+
+}
+
+// Streaming wrappers over the paginated token endpoints. Each walks every page
+// internally (respecting the rate limiter) and stops on a short page or an
+// `EmptyResult`, so building a full holder snapshot or NFT inventory is a single
+// `.try_collect()`.
+impl AsyncClient {
+    pub fn token_holder_list_all<'a>(&'a self, contract_address: &'a str, sort: &'a str) -> impl Stream<Item = Result<TokenHolder, EtherscanError>> + 'a {
+        self.paginate(DEFAULT_PAGE_OFFSET, move |page, offset| {
+            self.token_holder_list(contract_address, page, offset, sort)
+        })
+    }
+
+    pub fn erc20_token_balance_all<'a>(&'a self, address: &'a str) -> impl Stream<Item = Result<TokenBalance, EtherscanError>> + 'a {
+        self.paginate(DEFAULT_PAGE_OFFSET, move |page, offset| {
+            self.erc20_token_balance(address, page, offset)
+        })
+    }
+
+    pub fn erc721_token_inventory_all<'a>(&'a self, address: &'a str) -> impl Stream<Item = Result<TokenInventoryItem, EtherscanError>> + 'a {
+        self.paginate(DEFAULT_PAGE_OFFSET, move |page, offset| {
+            self.erc721_token_inventory(address, page, offset)
+        })
     }
 
+    pub fn erc721_token_inventory_by_contract_all<'a>(&'a self, contract_address: &'a str, address: &'a str) -> impl Stream<Item = Result<TokenInventoryItem, EtherscanError>> + 'a {
+        self.paginate(DEFAULT_PAGE_OFFSET, move |page, offset| {
+            self.erc721_token_inventory_by_contract(contract_address, address, page, offset)
+        })
+    }
 }
 
 // Gas Tracker API
 impl AsyncClient {
-    async fn estimate_confirmation_time(&self, gas_price: i64) -> Result<String, Error> {
+    pub async fn estimate_confirmation_time(&self, gas_price: i64) -> Result<u64, EtherscanError> {
         let params: GasPriceQuery<'_> = GasPriceQuery {
             gasprice: &format!("0x{:x}", gas_price),
         };
-        self.get("gastracker", "gasestimate", params).await
+        // The estimated confirmation time is returned as a number of seconds.
+        let seconds: String = self.get("gastracker", "gasestimate", params).await?;
+        seconds.parse().map_err(|_| EtherscanError::BadStatus(seconds))
     }
 
-    async fn gas_oracle(&self) -> Result<String, Error> {
+    pub async fn gas_oracle(&self) -> Result<GasOracle, EtherscanError> {
         self.get("gastracker", "gasoracle", ()).await
     }
 
-    async fn daily_average_gas_limit(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    // Apply the configured multiplier and ceiling to a wei-denominated price,
+    // keeping the arithmetic in integers via basis points.
+    fn bound_gas_price(&self, price: U256) -> U256 {
+        let bps: u64 = (self.gas_multiplier * 10_000.0).round() as u64;
+        let mut bounded: U256 = price * U256::from(bps) / U256::from(10_000u64);
+        if let Some(ceiling) = self.gas_ceiling {
+            if bounded > ceiling {
+                bounded = ceiling;
+            }
+        }
+        bounded
+    }
+
+    pub async fn daily_average_gas_limit(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -844,7 +1467,7 @@ impl AsyncClient {
         self.get("stats", "dailyavggaslimit", params).await
     }
 
-    async fn daily_total_gas_used(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_total_gas_used(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -853,7 +1476,7 @@ impl AsyncClient {
         self.get("stats", "dailygasused", params).await
     }
 
-    async fn daily_average_gas_price(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_average_gas_price(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -863,21 +1486,68 @@ impl AsyncClient {
     }
 }
 
+// A gas-price source that downstream signing/transaction libraries can plug in
+// instead of re-querying and parsing the oracle themselves.
+#[async_trait]
+pub trait GasOracleSource {
+    // A single suggested (legacy) gas price, in wei.
+    async fn fetch(&self) -> Result<U256, EtherscanError>;
+
+    // An EIP-1559 `(max_fee_per_gas, max_priority_fee_per_gas)` pair, in wei,
+    // derived from the oracle's suggested base fee and fast tier.
+    async fn estimate_eip1559_fees(&self) -> Result<(U256, U256), EtherscanError>;
+}
+
+#[async_trait]
+impl GasOracleSource for AsyncClient {
+    async fn fetch(&self) -> Result<U256, EtherscanError> {
+        let oracle: GasOracle = self.gas_oracle().await?;
+        Ok(self.bound_gas_price(gwei_to_wei(oracle.propose_gas_price)))
+    }
+
+    async fn estimate_eip1559_fees(&self) -> Result<(U256, U256), EtherscanError> {
+        let oracle: GasOracle = self.gas_oracle().await?;
+        let base_fee: U256 = gwei_to_wei(oracle.suggest_base_fee);
+        let fast: U256 = gwei_to_wei(oracle.fast_gas_price);
+        // Tip is the headroom of the fast tier over the base fee; bound it once,
+        // then budget for the base fee doubling before the (already-bounded) tip
+        // is added on top so the multiplier/ceiling isn't applied to it twice.
+        let max_priority_fee: U256 = self.bound_gas_price(fast.saturating_sub(base_fee));
+        let max_fee: U256 = base_fee * U256::from(2u64) + max_priority_fee;
+        Ok((max_fee, max_priority_fee))
+    }
+}
+
 // Stats API
 impl AsyncClient {
-    async fn total_eth_supply(&self) -> Result<String, Error> {
+    pub async fn total_eth_supply(&self) -> Result<U256, EtherscanError> {
+        let supply: String = self.get("stats", "ethsupply", ()).await?;
+        parse_u256(&supply)
+    }
+
+    pub async fn total_eth_supply_raw(&self) -> Result<String, EtherscanError> {
         self.get("stats", "ethsupply", ()).await
     }
 
-    async fn total_eth2_supply(&self) -> Result<String, Error> {
+    pub async fn total_eth2_supply(&self) -> Result<EthSupply, EtherscanError> {
         self.get("stats", "ethsupply2", ()).await
     }
 
-    async fn eth_price(&self) -> Result<String, Error> {
+    pub async fn total_eth2_supply_raw(&self) -> Result<String, EtherscanError> {
+        let result: serde_json::Value = self.get("stats", "ethsupply2", ()).await?;
+        Ok(result.to_string())
+    }
+
+    pub async fn eth_price(&self) -> Result<EthPrice, EtherscanError> {
         self.get("stats", "ethprice", ()).await
     }
 
-    async fn chain_size(&self, start_date: &str, end_date: &str, client_type: &str, sync_mode: &str, sort: &str) -> Result<String, Error> {
+    pub async fn eth_price_raw(&self) -> Result<String, EtherscanError> {
+        let result: serde_json::Value = self.get("stats", "ethprice", ()).await?;
+        Ok(result.to_string())
+    }
+
+    pub async fn chain_size(&self, start_date: &str, end_date: &str, client_type: &str, sync_mode: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: BlockchainSizeQuery<'_> = BlockchainSizeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -888,11 +1558,11 @@ impl AsyncClient {
         self.get("stats", "chainsize", params).await
     }
 
-    async fn total_node_count(&self) -> Result<String, Error> {
+    pub async fn total_node_count(&self) -> Result<String, EtherscanError> {
         self.get("stats", "nodecount", ()).await
     }
 
-    async fn daily_total_transaction_fee(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_total_transaction_fee(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -901,7 +1571,7 @@ impl AsyncClient {
         self.get("stats", "dailytxnsfee", params).await
     }
 
-    async fn daily_new_address_count(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_new_address_count(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -910,7 +1580,7 @@ impl AsyncClient {
         self.get("stats", "newaddress", params).await
     }
 
-    async fn daily_network_utilization(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_network_utilization(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -919,7 +1589,7 @@ impl AsyncClient {
         self.get("stats", "ethusd", params).await
     }
 
-    async fn daily_average_hash_rate(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_average_hash_rate(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -928,7 +1598,7 @@ impl AsyncClient {
         self.get("stats", "dailyhashrate", params).await
     }
 
-    async fn dailt_transaction_count(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn dailt_transaction_count(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -937,7 +1607,7 @@ impl AsyncClient {
         self.get("stats", "dailytxns", params).await
     }
 
-    async fn daily_average_difficulty(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_average_difficulty(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -946,7 +1616,7 @@ impl AsyncClient {
         self.get("stats", "dailyavgdifficulty", params).await
     }
 
-    async fn daily_market_cap_history(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_market_cap_history(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -955,7 +1625,7 @@ impl AsyncClient {
         self.get("stats", "ethdailymarketcap", params).await
     }
 
-    async fn daily_eth_price_history(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, Error> {
+    pub async fn daily_eth_price_history(&self, start_date: &str, end_date: &str, sort: &str) -> Result<String, EtherscanError> {
         let params: DateRangeQuery<'_> = DateRangeQuery {
             startdate: start_date,
             enddate: end_date,
@@ -976,7 +1646,7 @@ mod tests {
         let client: AsyncClient = AsyncClient::new("YourApiKeyToken");
 
         // Make the API call
-        let balance: String = client.get_balance("0xde0b295669a9fd93d5f28d9ec85e40f4cb697bae").await.unwrap();
+        let balance: U256 = client.get_balance("0xde0b295669a9fd93d5f28d9ec85e40f4cb697bae").await.unwrap();
 
         // Print the balance
         println!("Balance: {}", balance);